@@ -9,9 +9,14 @@
 extern crate alloc;
 
 pub mod padding_constants;
+#[cfg(feature = "pwbox")]
+pub mod pwbox;
 pub mod safe_box;
 pub mod safe_secretbox;
 pub mod safe_sign;
+#[cfg(feature = "serde")]
+pub mod sealed;
+pub mod session;
 pub mod types;
 
 pub(crate) mod macros;
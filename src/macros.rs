@@ -29,3 +29,47 @@ macro_rules! type_wrapper {
 }
 
 pub(crate) use type_wrapper;
+
+/// Alias of [`type_wrapper!`], for call sites that prefer the `newtype` naming.
+macro_rules! newtype_wrapper {
+    ($newtype_name:ident, $array_type_name:ty) => {
+        $crate::macros::type_wrapper!($newtype_name, $array_type_name);
+    };
+}
+
+pub(crate) use newtype_wrapper;
+
+/// Declare a [newtype] wrapper like [`type_wrapper!`], for secret material that should be erased
+/// from memory once it is no longer needed.
+///
+/// # Trait implementations
+///
+/// - [`From`] to wrap an array
+/// - [`AsRef`] to reference the wrapped array
+/// - Behind the `zeroize` feature, [`zeroize::Zeroize`] and [`zeroize::ZeroizeOnDrop`], so the
+///   wrapped bytes are overwritten with zeroes (via a volatile, compiler-fence-protected write)
+///   when the value is dropped
+///
+/// [newtype]: https://rust-unofficial.github.io/patterns/patterns/behavioural/newtype.html
+macro_rules! secret_type_wrapper {
+    ($newtype_name:ident, $array_type_name:ty) => {
+        #[derive(Clone)]
+        #[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+        #[repr(transparent)]
+        pub struct $newtype_name($array_type_name);
+
+        impl From<$array_type_name> for $newtype_name {
+            fn from(array: $array_type_name) -> Self {
+                $newtype_name(array)
+            }
+        }
+
+        impl AsRef<$array_type_name> for $newtype_name {
+            fn as_ref(&self) -> &$array_type_name {
+                &self.0
+            }
+        }
+    };
+}
+
+pub(crate) use secret_type_wrapper;
@@ -0,0 +1,235 @@
+//! Password-based encryption, modeled on the `pwbox` crate.
+//!
+//! [`PwBox`] derives a [`SafeSecretboxKey`] from a human password and a random salt using a
+//! configurable memory-hard key derivation function, then encrypts the data with
+//! [`safe_secretbox`]. The resulting container bundles everything needed to decrypt it again
+//! given only the password: the [`Kdf`] identifier and its cost parameters, the salt, the nonce,
+//! and the ciphertext.
+
+use alloc::boxed::Box;
+use alloc::vec;
+
+use crate::safe_secretbox::{
+    safe_secretbox, safe_secretbox_open, SafeSecretboxKey, SafeSecretboxNonce,
+};
+use crate::types::Plaintext;
+
+/// Length, in bytes, of a freshly generated [`PwBox::seal`] salt.
+const SALT_LEN: usize = 16;
+
+/// Which memory-hard key derivation function produced a [`PwBox`], along with its tunable cost
+/// parameters.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kdf {
+    /// Argon2id, as specified in RFC 9106.
+    Argon2id {
+        /// Amount of memory to use, in kibibytes.
+        memory_cost_kib: u32,
+        /// Number of passes over the memory.
+        time_cost: u32,
+        /// Degree of parallelism.
+        parallelism: u32,
+    },
+    /// scrypt, as specified in RFC 7914.
+    Scrypt {
+        /// CPU/memory cost, as the power-of-two exponent `N = 2^log_n`.
+        log_n: u8,
+        /// Block size parameter.
+        r: u32,
+        /// Parallelization parameter.
+        p: u32,
+    },
+}
+
+impl Kdf {
+    /// A reasonable default set of Argon2id parameters (19 MiB, 2 passes, 1 lane), matching the
+    /// OWASP minimum recommendation.
+    pub const DEFAULT_ARGON2ID: Kdf = Kdf::Argon2id {
+        memory_cost_kib: 19 * 1024,
+        time_cost: 2,
+        parallelism: 1,
+    };
+
+    /// A reasonable default set of scrypt parameters (`N = 2^15`, `r = 8`, `p = 1`).
+    pub const DEFAULT_SCRYPT: Kdf = Kdf::Scrypt {
+        log_n: 15,
+        r: 8,
+        p: 1,
+    };
+
+    /// Derive a [`SafeSecretboxKey`] from `password` and `salt` using these parameters.
+    ///
+    /// Returns `Err` if the parameters are out of range for the chosen KDF, or if `salt` doesn't
+    /// meet its minimum length — both of which are possible when `self`/`salt` came from a
+    /// deserialized, untrusted [`PwBox`], rather than from [`PwBox::seal`].
+    fn derive(&self, password: &[u8], salt: &[u8]) -> Result<SafeSecretboxKey, KdfError> {
+        let mut key = sodalite::SecretboxKey::default();
+        match *self {
+            Kdf::Argon2id {
+                memory_cost_kib,
+                time_cost,
+                parallelism,
+            } => {
+                let params = argon2::Params::new(memory_cost_kib, time_cost, parallelism, Some(key.len()))
+                    .map_err(|_| KdfError)?;
+                let argon2 = argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+                argon2
+                    .hash_password_into(password, salt, &mut key)
+                    .map_err(|_| KdfError)?;
+            }
+            Kdf::Scrypt { log_n, r, p } => {
+                let params = scrypt::Params::new(log_n, r, p, key.len()).map_err(|_| KdfError)?;
+                scrypt::scrypt(password, salt, &params, &mut key).map_err(|_| KdfError)?;
+            }
+        }
+        Ok(key.into())
+    }
+}
+
+/// Opaque marker that [`Kdf::derive`] was given out-of-range parameters or an unsuitable salt.
+struct KdfError;
+
+/// A password-encrypted container, bundling the [`Kdf`] used, the salt, the nonce, and the
+/// ciphertext.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PwBox {
+    kdf: Kdf,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::sealed::base64_bytes"))]
+    salt: Box<[u8]>,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::sealed::base64_bytes"))]
+    nonce: Box<[u8]>,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::sealed::base64_bytes"))]
+    ciphertext: Box<[u8]>,
+}
+
+impl PwBox {
+    /// Encrypt `plaintext` under `password`, deriving the key with `kdf` and a freshly generated
+    /// random salt and nonce.
+    #[cfg(feature = "rand")]
+    pub fn seal(plaintext: &Plaintext, password: &[u8], kdf: Kdf) -> Self {
+        use rand::RngCore;
+
+        let mut salt = vec![0_u8; SALT_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut salt);
+
+        let mut nonce_bytes = sodalite::SecretboxNonce::default();
+        rand::rngs::OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce: SafeSecretboxNonce = nonce_bytes.into();
+
+        let key = kdf
+            .derive(password, &salt)
+            .expect("PwBox::seal: invalid KDF parameters");
+        let ciphertext = safe_secretbox(plaintext, &nonce, &key);
+
+        PwBox {
+            kdf,
+            salt: salt.into_boxed_slice(),
+            nonce: Box::from(nonce.as_ref().as_slice()),
+            ciphertext,
+        }
+    }
+
+    /// Re-derive the key from `password` and the stored salt and parameters, and decrypt the
+    /// bundled ciphertext.
+    ///
+    /// Returns `None` if `password` is wrong, or the container is malformed (including a `kdf`
+    /// with out-of-range parameters, or a `salt` that the KDF rejects).
+    pub fn unseal(&self, password: &[u8]) -> Option<Box<Plaintext>> {
+        let nonce = bytes_to_nonce(&self.nonce)?;
+        let key = self.kdf.derive(password, &self.salt).ok()?;
+        safe_secretbox_open(&self.ciphertext, &nonce.into(), &key)
+    }
+}
+
+/// Copy `bytes` into a [`SafeSecretboxNonce`]-sized array, or return `None` if the length
+/// doesn't match.
+fn bytes_to_nonce(bytes: &[u8]) -> Option<sodalite::SecretboxNonce> {
+    let mut nonce = sodalite::SecretboxNonce::default();
+    if bytes.len() != nonce.len() {
+        return None;
+    }
+    nonce.copy_from_slice(bytes);
+    Some(nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format; // required by proptest macros
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Cheap Argon2id parameters, so the tests run fast.
+    const TEST_KDF: Kdf = Kdf::Argon2id {
+        memory_cost_kib: 8,
+        time_cost: 1,
+        parallelism: 1,
+    };
+
+    /// Property: [`PwBox::seal`] and [`PwBox::unseal`] round-trip with the correct password.
+    #[test]
+    fn prop_pwbox_roundtrip() {
+        proptest!(|(password: Box<[u8]>, message: Box<Plaintext>)| {
+            let sealed = PwBox::seal(&message, &password, TEST_KDF);
+            let plaintext = sealed.unseal(&password).expect("unseal failed");
+            prop_assert_eq!(plaintext, message);
+        });
+    }
+
+    /// Property: [`PwBox::unseal`] with the wrong password returns `None`.
+    #[test]
+    fn prop_pwbox_wrong_password_fails() {
+        proptest!(|(password: Box<[u8]>, wrong_password: Box<[u8]>, message: Box<Plaintext>)| {
+            prop_assume!(password != wrong_password);
+
+            let sealed = PwBox::seal(&message, &password, TEST_KDF);
+            prop_assert_eq!(sealed.unseal(&wrong_password), None);
+        });
+    }
+
+    /// [`PwBox::unseal`] returns `None`, instead of panicking, when a deserialized container has
+    /// out-of-range KDF parameters or a salt too short for the KDF to accept.
+    #[test]
+    fn pwbox_corrupt_params_dont_panic() {
+        let mut sealed = PwBox::seal(b"message", b"password", TEST_KDF);
+
+        sealed.kdf = Kdf::Argon2id {
+            memory_cost_kib: 0,
+            time_cost: 1,
+            parallelism: 1,
+        };
+        assert_eq!(sealed.unseal(b"password"), None);
+
+        sealed.kdf = Kdf::Scrypt {
+            log_n: 255,
+            r: 8,
+            p: 1,
+        };
+        assert_eq!(sealed.unseal(b"password"), None);
+
+        sealed.kdf = TEST_KDF;
+        sealed.salt = vec![0_u8; 2].into_boxed_slice();
+        assert_eq!(sealed.unseal(b"password"), None);
+    }
+
+    /// Property: a serialized [`PwBox`] round-trips across JSON.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn prop_pwbox_serde_roundtrip() {
+        proptest!(|(password: Box<[u8]>, message: Box<Plaintext>)| {
+            let sealed = PwBox::seal(&message, &password, TEST_KDF);
+
+            let json = serde_json::to_string(&sealed).expect("serialize failed");
+            let deserialized: PwBox = serde_json::from_str(&json).expect("deserialize failed");
+
+            let plaintext = deserialized.unseal(&password).expect("unseal failed");
+            prop_assert_eq!(plaintext, message);
+        });
+    }
+}
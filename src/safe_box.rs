@@ -40,16 +40,17 @@ use alloc::boxed::Box;
 use alloc::vec;
 use core::option::Option;
 
-use sodalite::{BoxPublicKey, BoxSecretKey};
+use sodalite::{BoxNonce, BoxPublicKey, BoxSecretKey};
 
-use crate::macros::type_wrapper;
+use crate::macros::{secret_type_wrapper, type_wrapper};
 use crate::padding_constants;
 use crate::padding_helpers::{pad_zeroes, unpad_zeroes};
 use crate::types::{Ciphertext, Plaintext, SafeSecureSeed};
 
 type_wrapper!(SafeBoxPublicKey, sodalite::BoxPublicKey);
-type_wrapper!(SafeBoxSecretKey, sodalite::BoxSecretKey);
+secret_type_wrapper!(SafeBoxSecretKey, sodalite::BoxSecretKey);
 type_wrapper!(SafeBoxNonce, sodalite::BoxNonce);
+secret_type_wrapper!(SafeBoxSharedKey, [u8; 32]);
 
 /// Generate a new random secret key and corresponding public key.
 #[cfg(feature = "rand")]
@@ -143,9 +144,142 @@ pub fn safe_box_open(
     unpad_zeroes(padding_constants::BOX_ZEROBYTES, padded_plaintext)
 }
 
+/// Precompute the shared secret for `public_key` and `secret_key`.
+///
+/// Reuse the returned [`SafeBoxSharedKey`] with [`safe_box_afternm`]/[`safe_box_open_afternm`] to
+/// avoid recomputing the Curve25519 shared secret for every message exchanged with the same peer.
+///
+/// This wraps [`sodalite::box_beforenm`].
+pub fn safe_box_beforenm(
+    public_key: &SafeBoxPublicKey,
+    secret_key: &SafeBoxSecretKey,
+) -> SafeBoxSharedKey {
+    let mut shared_key = [0_u8; 32];
+    sodalite::box_beforenm(&mut shared_key, public_key.as_ref(), secret_key.as_ref());
+    shared_key.into()
+}
+
+/// Encrypt and authenticate a message using a `shared_key` precomputed by [`safe_box_beforenm`].
+///
+/// This wraps [`sodalite::box_afternm`].
+pub fn safe_box_afternm(
+    plaintext: &Plaintext,
+    nonce: &SafeBoxNonce,
+    shared_key: &SafeBoxSharedKey,
+) -> Box<Ciphertext> {
+    // Pad plaintext for input to `crypto_box_afternm`, same as `safe_box`.
+    let padded_plaintext = pad_zeroes(padding_constants::BOX_ZEROBYTES, plaintext);
+
+    // Precondition: Output buffer size must match input buffer
+    let mut padded_ciphertext = vec![0_u8; padded_plaintext.len()];
+
+    sodalite::box_afternm(
+        &mut padded_ciphertext,
+        &padded_plaintext,
+        nonce.as_ref(),
+        shared_key.as_ref(),
+    )
+    // Safety: This should be infallible.
+    .expect("safe_box_afternm: box_afternm failed!");
+
+    // Unpad ciphertext output, same as `safe_box`.
+    unpad_zeroes(padding_constants::BOX_BOXZEROBYTES, padded_ciphertext)
+        // Safety: This should be infallible.
+        .expect("safe_box_afternm: unpad_zeroes failed!")
+}
+
+/// Decrypt and verify a message using a `shared_key` precomputed by [`safe_box_beforenm`].
+///
+/// This wraps [`sodalite::box_open_afternm`].
+pub fn safe_box_open_afternm(
+    ciphertext: &Ciphertext,
+    nonce: &SafeBoxNonce,
+    shared_key: &SafeBoxSharedKey,
+) -> Option<Box<Plaintext>> {
+    // Pad ciphertext for input to `crypto_box_open_afternm`, same as `safe_box_open`.
+    let padded_ciphertext = pad_zeroes(padding_constants::BOX_BOXZEROBYTES, ciphertext);
+
+    // Precondition: Output buffer size must match input buffer
+    let mut padded_plaintext = vec![0_u8; padded_ciphertext.len()];
+
+    sodalite::box_open_afternm(
+        &mut padded_plaintext,
+        &padded_ciphertext,
+        nonce.as_ref(),
+        shared_key.as_ref(),
+    )
+    .ok()?;
+
+    // Unpad plaintext output, same as `safe_box_open`.
+    unpad_zeroes(padding_constants::BOX_ZEROBYTES, padded_plaintext)
+}
+
+/// Decrypt and verify a message from `public_key` to `secret_key`, returning the recovered
+/// plaintext in a wrapper that zeroizes it on drop.
+///
+/// This wraps [`safe_box_open`].
+#[cfg(feature = "zeroize")]
+pub fn safe_box_open_zeroizing(
+    ciphertext: &Ciphertext,
+    nonce: &SafeBoxNonce,
+    public_key: &SafeBoxPublicKey,
+    secret_key: &SafeBoxSecretKey,
+) -> Option<zeroize::Zeroizing<Box<Plaintext>>> {
+    safe_box_open(ciphertext, nonce, public_key, secret_key).map(zeroize::Zeroizing::new)
+}
+
+/// Length, in bytes, of a [`SafeBoxNonce`].
+const NONCE_LEN: usize = core::mem::size_of::<sodalite::BoxNonce>();
+
+/// Encrypt and authenticate a message from `secret_key` to `public_key`, generating a fresh
+/// random nonce and prepending it to the returned ciphertext.
+///
+/// This mirrors the `crypto_box` crate's combined (`_easy`) format: the output is laid out as
+/// `nonce || ciphertext`, which [`safe_box_open_easy`] expects back.
+///
+/// This wraps [`safe_box`].
+#[cfg(feature = "rand")]
+pub fn safe_box_easy(
+    plaintext: &Plaintext,
+    public_key: &SafeBoxPublicKey,
+    secret_key: &SafeBoxSecretKey,
+) -> Box<[u8]> {
+    use rand::RngCore;
+
+    let mut nonce_bytes = BoxNonce::default();
+    rand::rngs::OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = SafeBoxNonce::from(nonce_bytes);
+
+    let ciphertext = safe_box(plaintext, &nonce, public_key, secret_key);
+
+    [nonce.as_ref().as_slice(), &ciphertext].concat().into_boxed_slice()
+}
+
+/// Decrypt and verify a message produced by [`safe_box_easy`].
+///
+/// Splits the leading nonce off the `nonce || ciphertext` layout before delegating to
+/// [`safe_box_open`]. Returns `None` if `combined` is shorter than a nonce.
+#[cfg(feature = "rand")]
+pub fn safe_box_open_easy(
+    combined: &[u8],
+    public_key: &SafeBoxPublicKey,
+    secret_key: &SafeBoxSecretKey,
+) -> Option<Box<Plaintext>> {
+    if combined.len() < NONCE_LEN {
+        return None;
+    }
+
+    let (nonce_bytes, ciphertext) = combined.split_at(NONCE_LEN);
+    let mut nonce = BoxNonce::default();
+    nonce.copy_from_slice(nonce_bytes);
+
+    safe_box_open(ciphertext, &nonce.into(), public_key, secret_key)
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format; // required by proptest macros
+    use alloc::vec::Vec;
 
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
@@ -180,4 +314,78 @@ mod tests {
             Ok(())
         }
     }
+
+    /// Property: [`safe_box_easy`] and [`safe_box_open_easy`] form a bijection.
+    #[test]
+    fn prop_box_easy_bijection() {
+        proptest!(|(our_seed: SecureSeed, their_seed: SecureSeed, message: Box<Plaintext>)| {
+            prop_assume!(our_seed != their_seed);
+            check(our_seed.into(), their_seed.into(), message)?;
+        });
+
+        fn check(
+            sender_seed: SafeSecureSeed,
+            receiver_seed: SafeSecureSeed,
+            message: Box<Plaintext>,
+        ) -> TestCaseResult {
+            let (sender_pk, sender_sk) = safe_box_keypair_seed(&sender_seed);
+            let (receiver_pk, receiver_sk) = safe_box_keypair_seed(&receiver_seed);
+
+            let combined = safe_box_easy(&message, &receiver_pk, &sender_sk);
+            let plaintext = safe_box_open_easy(&combined, &sender_pk, &receiver_sk)
+                .expect("safe_box_open_easy failed");
+
+            prop_assert_eq!(plaintext, message);
+
+            Ok(())
+        }
+    }
+
+    /// Property: [`safe_box_afternm`] produces the same ciphertext as [`safe_box`] for identical
+    /// inputs, and the precomputed shared key is symmetric between the two parties.
+    #[test]
+    fn prop_box_afternm_matches_box() {
+        proptest!(|(our_seed: SecureSeed, their_seed: SecureSeed, nonce: BoxNonce, message: Box<Plaintext>)| {
+            prop_assume!(our_seed != their_seed);
+            check(our_seed.into(), their_seed.into(), nonce.into(), message)?;
+        });
+
+        fn check(
+            sender_seed: SafeSecureSeed,
+            receiver_seed: SafeSecureSeed,
+            nonce: SafeBoxNonce,
+            message: Box<Plaintext>,
+        ) -> TestCaseResult {
+            let (sender_pk, sender_sk) = safe_box_keypair_seed(&sender_seed);
+            let (receiver_pk, receiver_sk) = safe_box_keypair_seed(&receiver_seed);
+
+            let sender_shared_key = safe_box_beforenm(&receiver_pk, &sender_sk);
+            let receiver_shared_key = safe_box_beforenm(&sender_pk, &receiver_sk);
+            prop_assert_eq!(sender_shared_key.as_ref(), receiver_shared_key.as_ref());
+
+            let ciphertext = safe_box(&message, &nonce, &receiver_pk, &sender_sk);
+            let ciphertext_afternm = safe_box_afternm(&message, &nonce, &sender_shared_key);
+            prop_assert_eq!(&ciphertext_afternm, &ciphertext);
+
+            let plaintext = safe_box_open_afternm(&ciphertext_afternm, &nonce, &receiver_shared_key)
+                .expect("safe_box_open_afternm failed");
+            prop_assert_eq!(plaintext, message);
+
+            Ok(())
+        }
+    }
+
+    /// Property: [`safe_box_open_easy`] rejects input shorter than a nonce.
+    #[test]
+    fn prop_box_open_easy_rejects_short_input() {
+        proptest!(|(our_seed: SecureSeed, their_seed: SecureSeed, short: Vec<u8>)| {
+            prop_assume!(our_seed != their_seed);
+            prop_assume!(short.len() < NONCE_LEN);
+
+            let (_, sender_sk) = safe_box_keypair_seed(&our_seed.into());
+            let (receiver_pk, _) = safe_box_keypair_seed(&their_seed.into());
+
+            prop_assert_eq!(safe_box_open_easy(&short, &receiver_pk, &sender_sk), None);
+        });
+    }
 }
@@ -19,12 +19,12 @@ use alloc::boxed::Box;
 use alloc::vec;
 use core::option::Option;
 
-use crate::macros::newtype_wrapper;
+use crate::macros::{newtype_wrapper, secret_type_wrapper};
 use crate::padding_constants;
 use crate::padding_helpers::{pad_zeroes, unpad_zeroes};
 use crate::types::{Ciphertext, Plaintext};
 
-newtype_wrapper!(SafeSecretboxKey, sodalite::SecretboxKey);
+secret_type_wrapper!(SafeSecretboxKey, sodalite::SecretboxKey);
 newtype_wrapper!(SafeSecretboxNonce, sodalite::SecretboxNonce);
 
 /// Encrypt and authenticate a message using `key`.
@@ -98,9 +98,63 @@ pub fn safe_secretbox_open(
     unpad_zeroes(padding_constants::BOX_ZEROBYTES, padded_plaintext)
 }
 
+/// Decrypt and verify a message using `key`, returning the recovered plaintext in a wrapper that
+/// zeroizes it on drop.
+///
+/// This wraps [`safe_secretbox_open`].
+#[cfg(feature = "zeroize")]
+pub fn safe_secretbox_open_zeroizing(
+    ciphertext: &Ciphertext,
+    nonce: &SafeSecretboxNonce,
+    key: &SafeSecretboxKey,
+) -> Option<zeroize::Zeroizing<Box<Plaintext>>> {
+    safe_secretbox_open(ciphertext, nonce, key).map(zeroize::Zeroizing::new)
+}
+
+/// Length, in bytes, of a [`SafeSecretboxNonce`].
+const NONCE_LEN: usize = core::mem::size_of::<sodalite::SecretboxNonce>();
+
+/// Encrypt and authenticate a message using `key`, generating a fresh random nonce and
+/// prepending it to the returned ciphertext.
+///
+/// This mirrors the `crypto_box` crate's combined (`_easy`) format: the output is laid out as
+/// `nonce || ciphertext`, which [`safe_secretbox_open_easy`] expects back.
+///
+/// This wraps [`safe_secretbox`].
+#[cfg(feature = "rand")]
+pub fn safe_secretbox_easy(plaintext: &Plaintext, key: &SafeSecretboxKey) -> Box<[u8]> {
+    use rand::RngCore;
+
+    let mut nonce_bytes = sodalite::SecretboxNonce::default();
+    rand::rngs::OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = SafeSecretboxNonce::from(nonce_bytes);
+
+    let ciphertext = safe_secretbox(plaintext, &nonce, key);
+
+    [nonce.as_ref().as_slice(), &ciphertext].concat().into_boxed_slice()
+}
+
+/// Decrypt and verify a message produced by [`safe_secretbox_easy`].
+///
+/// Splits the leading nonce off the `nonce || ciphertext` layout before delegating to
+/// [`safe_secretbox_open`]. Returns `None` if `combined` is shorter than a nonce.
+#[cfg(feature = "rand")]
+pub fn safe_secretbox_open_easy(combined: &[u8], key: &SafeSecretboxKey) -> Option<Box<Plaintext>> {
+    if combined.len() < NONCE_LEN {
+        return None;
+    }
+
+    let (nonce_bytes, ciphertext) = combined.split_at(NONCE_LEN);
+    let mut nonce = sodalite::SecretboxNonce::default();
+    nonce.copy_from_slice(nonce_bytes);
+
+    safe_secretbox_open(ciphertext, &nonce.into(), key)
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format; // required by proptest macros
+    use alloc::vec::Vec;
 
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
@@ -129,4 +183,31 @@ mod tests {
             Ok(())
         }
     }
+
+    /// Property: [`safe_secretbox_easy`] and [`safe_secretbox_open_easy`] form a bijection.
+    #[test]
+    fn prop_secretbox_easy_bijection() {
+        proptest!(|(key: SecretboxKey, message: Box<Plaintext>)| {
+            check(key.into(), message)?;
+        });
+
+        fn check(key: SafeSecretboxKey, message: Box<Plaintext>) -> TestCaseResult {
+            let combined = safe_secretbox_easy(&message, &key);
+            let plaintext =
+                safe_secretbox_open_easy(&combined, &key).expect("safe_secretbox_open_easy failed");
+
+            prop_assert_eq!(plaintext, message);
+
+            Ok(())
+        }
+    }
+
+    /// Property: [`safe_secretbox_open_easy`] rejects input shorter than a nonce.
+    #[test]
+    fn prop_secretbox_open_easy_rejects_short_input() {
+        proptest!(|(key: SecretboxKey, short: Vec<u8>)| {
+            prop_assume!(short.len() < NONCE_LEN);
+            prop_assert_eq!(safe_secretbox_open_easy(&short, &key.into()), None);
+        });
+    }
 }
@@ -15,11 +15,11 @@ use alloc::vec;
 
 use sodalite::SignPublicKey;
 
-use crate::macros::newtype_wrapper;
+use crate::macros::{newtype_wrapper, secret_type_wrapper};
 use crate::types::{SafeSecureSeed, SignedMessage, UnsignedMessage, VerifiedMessage};
 
 newtype_wrapper!(SafeSignPublicKey, sodalite::SignPublicKey);
-newtype_wrapper!(SafeSignSecretKey, sodalite::SignSecretKey);
+secret_type_wrapper!(SafeSignSecretKey, sodalite::SignSecretKey);
 
 /// Generate a new random secret key and corresponding public key.
 #[cfg(feature = "rand")]
@@ -75,6 +75,49 @@ pub fn safe_sign_attached_open(
     Some(verified_message.into())
 }
 
+/// Sign a message using `secret_key`, returning just the signature, detached from the message.
+///
+/// This wraps [`sodalite::sign_attached`], discarding the copy of the message that it appends
+/// after the signature.
+pub fn safe_sign_detached(
+    message: &UnsignedMessage,
+    secret_key: &SafeSignSecretKey,
+) -> Box<[u8; sodalite::SIGN_LEN]> {
+    // Precondition: Output buffer size must match input buffer plus signature size
+    let mut signed_message = vec![0_u8; message.len() + sodalite::SIGN_LEN];
+
+    sodalite::sign_attached(&mut signed_message, message, secret_key.as_ref());
+
+    let mut signature = Box::new([0_u8; sodalite::SIGN_LEN]);
+    signature.copy_from_slice(&signed_message[..sodalite::SIGN_LEN]);
+    signature
+}
+
+/// Verify a detached `signature` of `message`, produced by [`safe_sign_detached`].
+///
+/// This wraps [`sodalite::sign_attached_open`], reattaching `signature` to `message` before
+/// verifying.
+pub fn safe_sign_verify_detached(
+    message: &UnsignedMessage,
+    signature: &[u8; sodalite::SIGN_LEN],
+    public_key: &SafeSignPublicKey,
+) -> bool {
+    let signed_message = [signature.as_slice(), message].concat();
+    let mut verified_message = vec![0_u8; signed_message.len()];
+
+    let verified_len = match sodalite::sign_attached_open(
+        &mut verified_message,
+        &signed_message,
+        public_key.as_ref(),
+    ) {
+        Ok(verified_len) => verified_len,
+        Err(_) => return false,
+    };
+
+    verified_message.truncate(verified_len);
+    verified_message == message
+}
+
 /// XXX: Work around Rust providing [`Default`] only for arrays up to size 32.
 fn sign_secret_key_default() -> sodalite::SignSecretKey {
     [u8::default(); sodalite::SIGN_SECRET_KEY_LEN]
@@ -109,4 +152,38 @@ mod tests {
             Ok(())
         }
     }
+
+    /// Property: [`safe_sign_detached`] verifies against the original message with
+    /// [`safe_sign_verify_detached`], but not against a mutated one.
+    #[test]
+    fn prop_sign_detached_verifies_original_not_mutated() {
+        proptest!(|(seed: SecureSeed, message: Box<UnsignedMessage>, mutation: u8)| {
+            check(seed.into(), message, mutation)?;
+        });
+
+        fn check(
+            seed: SafeSecureSeed,
+            message: Box<UnsignedMessage>,
+            mutation: u8,
+        ) -> TestCaseResult {
+            let (signer_pk, signer_sk) = safe_sign_keypair_seed(&seed);
+
+            let signature = safe_sign_detached(&message, &signer_sk);
+            prop_assert!(safe_sign_verify_detached(&message, &signature, &signer_pk));
+
+            let mut mutated_message = message.clone();
+            if mutated_message.is_empty() {
+                mutated_message = vec![mutation].into();
+            } else {
+                mutated_message[0] ^= mutation | 1;
+            }
+            prop_assert!(!safe_sign_verify_detached(
+                &mutated_message,
+                &signature,
+                &signer_pk
+            ));
+
+            Ok(())
+        }
+    }
 }
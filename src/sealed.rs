@@ -0,0 +1,189 @@
+//! Portable, serde-serializable containers for encrypted data.
+//!
+//! [`SealedSecretbox`] and [`SealedBox`] bundle a ciphertext together with the nonce it was
+//! produced with, so the pair can travel as a single self-contained value instead of callers
+//! inventing their own wire format. Byte fields are encoded as base64 strings, so the serialized
+//! form is JSON/TOML friendly.
+
+use alloc::boxed::Box;
+
+use serde::{Deserialize, Serialize};
+
+use crate::safe_box::{safe_box, safe_box_open, SafeBoxNonce, SafeBoxPublicKey, SafeBoxSecretKey};
+use crate::safe_secretbox::{safe_secretbox, safe_secretbox_open, SafeSecretboxKey, SafeSecretboxNonce};
+use crate::types::{Ciphertext, Plaintext};
+
+/// A [`safe_secretbox`] ciphertext, bundled with its nonce, in a portable serde representation.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SealedSecretbox {
+    #[serde(with = "base64_bytes")]
+    nonce: Box<[u8]>,
+
+    #[serde(with = "base64_bytes")]
+    ciphertext: Box<Ciphertext>,
+}
+
+impl SealedSecretbox {
+    /// Encrypt and authenticate `plaintext` using `nonce` and `key`, bundling the ciphertext
+    /// together with the nonce.
+    ///
+    /// This wraps [`safe_secretbox`].
+    pub fn seal(plaintext: &Plaintext, nonce: &SafeSecretboxNonce, key: &SafeSecretboxKey) -> Self {
+        SealedSecretbox {
+            nonce: Box::from(nonce.as_ref().as_slice()),
+            ciphertext: safe_secretbox(plaintext, nonce, key),
+        }
+    }
+
+    /// Decrypt and verify the bundled ciphertext using `key`.
+    ///
+    /// This wraps [`safe_secretbox_open`].
+    pub fn unseal(&self, key: &SafeSecretboxKey) -> Option<Box<Plaintext>> {
+        let nonce: SafeSecretboxNonce = bytes_to_nonce(&self.nonce)?.into();
+        safe_secretbox_open(&self.ciphertext, &nonce, key)
+    }
+}
+
+/// A [`safe_box`] ciphertext, bundled with its nonce, in a portable serde representation.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SealedBox {
+    #[serde(with = "base64_bytes")]
+    nonce: Box<[u8]>,
+
+    #[serde(with = "base64_bytes")]
+    ciphertext: Box<Ciphertext>,
+}
+
+impl SealedBox {
+    /// Encrypt and authenticate `plaintext` from `secret_key` to `public_key` using `nonce`,
+    /// bundling the ciphertext together with the nonce.
+    ///
+    /// This wraps [`safe_box`].
+    pub fn seal(
+        plaintext: &Plaintext,
+        nonce: &SafeBoxNonce,
+        public_key: &SafeBoxPublicKey,
+        secret_key: &SafeBoxSecretKey,
+    ) -> Self {
+        SealedBox {
+            nonce: Box::from(nonce.as_ref().as_slice()),
+            ciphertext: safe_box(plaintext, nonce, public_key, secret_key),
+        }
+    }
+
+    /// Decrypt and verify the bundled ciphertext from `public_key` to `secret_key`.
+    ///
+    /// This wraps [`safe_box_open`].
+    pub fn unseal(
+        &self,
+        public_key: &SafeBoxPublicKey,
+        secret_key: &SafeBoxSecretKey,
+    ) -> Option<Box<Plaintext>> {
+        let nonce: SafeBoxNonce = bytes_to_nonce(&self.nonce)?.into();
+        safe_box_open(&self.ciphertext, &nonce, public_key, secret_key)
+    }
+}
+
+/// Copy `bytes` into a fixed-size nonce array, or return `None` if the length doesn't match.
+fn bytes_to_nonce<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+    let mut nonce = [0_u8; N];
+    if bytes.len() != nonce.len() {
+        return None;
+    }
+    nonce.copy_from_slice(bytes);
+    Some(nonce)
+}
+
+/// (De)serialize a byte slice as a base64 string.
+pub(crate) mod base64_bytes {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        STANDARD.encode(bytes).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Box<[u8]>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD
+            .decode(encoded)
+            .map(Vec::into_boxed_slice)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format; // required by proptest macros
+
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+    use sodalite::{BoxNonce, SecretboxKey, SecretboxNonce};
+
+    use super::*;
+    use crate::safe_box::safe_box_keypair_seed;
+    use crate::types::SecureSeed;
+
+    /// Property: a serialized [`SealedSecretbox`] round-trips back to the original plaintext.
+    #[test]
+    fn prop_sealed_secretbox_roundtrip() {
+        proptest!(|(key: SecretboxKey, nonce: SecretboxNonce, message: Box<Plaintext>)| {
+            check(key.into(), nonce.into(), message)?;
+        });
+
+        fn check(
+            key: SafeSecretboxKey,
+            nonce: SafeSecretboxNonce,
+            message: Box<Plaintext>,
+        ) -> TestCaseResult {
+            let sealed = SealedSecretbox::seal(&message, &nonce, &key);
+
+            let json = serde_json::to_string(&sealed).expect("serialize failed");
+            let deserialized: SealedSecretbox =
+                serde_json::from_str(&json).expect("deserialize failed");
+
+            let plaintext = deserialized.unseal(&key).expect("unseal failed");
+            prop_assert_eq!(plaintext, message);
+
+            Ok(())
+        }
+    }
+
+    /// Property: a serialized [`SealedBox`] round-trips back to the original plaintext.
+    #[test]
+    fn prop_sealed_box_roundtrip() {
+        proptest!(|(our_seed: SecureSeed, their_seed: SecureSeed, nonce: BoxNonce, message: Box<Plaintext>)| {
+            prop_assume!(our_seed != their_seed);
+            check(our_seed.into(), their_seed.into(), nonce.into(), message)?;
+        });
+
+        fn check(
+            sender_seed: crate::types::SafeSecureSeed,
+            receiver_seed: crate::types::SafeSecureSeed,
+            nonce: SafeBoxNonce,
+            message: Box<Plaintext>,
+        ) -> TestCaseResult {
+            let (sender_pk, sender_sk) = safe_box_keypair_seed(&sender_seed);
+            let (receiver_pk, receiver_sk) = safe_box_keypair_seed(&receiver_seed);
+
+            let sealed = SealedBox::seal(&message, &nonce, &receiver_pk, &sender_sk);
+
+            let json = serde_json::to_string(&sealed).expect("serialize failed");
+            let deserialized: SealedBox = serde_json::from_str(&json).expect("deserialize failed");
+
+            let plaintext = deserialized
+                .unseal(&sender_pk, &receiver_sk)
+                .expect("unseal failed");
+            prop_assert_eq!(plaintext, message);
+
+            Ok(())
+        }
+    }
+}
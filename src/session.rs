@@ -0,0 +1,314 @@
+//! Stateful sessions that automate nonce sequencing.
+//!
+//! The module docs for [`crate::safe_box`] and [`crate::safe_secretbox`] stress that the caller
+//! is responsible for nonce uniqueness, recommending a monotonic counter (or, for two `box`
+//! peers, the even/odd lexicographic convention). [`SecretboxSession`] and [`BoxSession`]
+//! automate that scheme: each holds the key material and a `u64` counter, encoded little-endian
+//! into the low bytes of the nonce, and each rejects any nonce at or below the highest one
+//! already accepted from the peer, to block replays.
+
+use alloc::boxed::Box;
+
+use crate::safe_box::{safe_box, safe_box_open, SafeBoxNonce, SafeBoxPublicKey, SafeBoxSecretKey};
+use crate::safe_secretbox::{
+    safe_secretbox, safe_secretbox_open, SafeSecretboxKey, SafeSecretboxNonce,
+};
+use crate::types::{Ciphertext, Plaintext};
+
+/// Which of the two peers sharing a [`SecretboxSession`] key a session represents.
+///
+/// Unlike [`BoxSession`], `crypto_secretbox` has no public keys for the two peers to compare
+/// lexicographically, so there is nothing a `SecretboxSession` can derive a role from on its
+/// own: the two peers must agree out-of-band on which one is [`Initiator`][Self::Initiator] and
+/// which is [`Responder`][Self::Responder], mirroring the even/odd nonce convention from
+/// [`crate::safe_box`]'s module docs. **A single key must never back two `Initiator` sessions
+/// (or two `Responder` sessions)** — that reintroduces the nonce reuse this module exists to
+/// prevent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SecretboxRole {
+    /// Sends on even counters (0, 2, 4, ...).
+    Initiator,
+    /// Sends on odd counters (1, 3, 5, ...).
+    Responder,
+}
+
+/// A [`crate::safe_secretbox`] session that sequences nonces with a monotonic counter.
+///
+/// Because the two peers share a single symmetric `key`, a session on its own only tracks
+/// "nonces we have sent" and "nonces we have accepted" — it cannot tell its own messages apart
+/// from the peer's by the key alone. Two sessions on the same `key` that send (rather than only
+/// receive) **must** be constructed with opposite [`SecretboxRole`]s, or both will start their
+/// counter at the same value and reuse nonces.
+pub struct SecretboxSession {
+    key: SafeSecretboxKey,
+    send_counter: u64,
+    highest_received: Option<u64>,
+}
+
+impl SecretboxSession {
+    /// Start a new session using `key`, with the send counter starting at the value for `role`.
+    pub fn new(key: SafeSecretboxKey, role: SecretboxRole) -> Self {
+        let send_counter = match role {
+            SecretboxRole::Initiator => 0,
+            SecretboxRole::Responder => 1,
+        };
+
+        SecretboxSession {
+            key,
+            send_counter,
+            highest_received: None,
+        }
+    }
+
+    /// Encrypt and authenticate `plaintext`, using and then advancing the session's counter.
+    ///
+    /// Returns the nonce alongside the ciphertext; pass both to the peer's [`Self::decrypt`].
+    pub fn encrypt_next(
+        &mut self,
+        plaintext: &Plaintext,
+    ) -> (SafeSecretboxNonce, Box<Ciphertext>) {
+        let nonce = secretbox_counter_nonce(self.send_counter);
+        self.send_counter = self
+            .send_counter
+            .checked_add(2)
+            .expect("SecretboxSession: counter exhausted");
+
+        let ciphertext = safe_secretbox(plaintext, &nonce, &self.key);
+        (nonce, ciphertext)
+    }
+
+    /// Decrypt and verify `ciphertext` using `nonce`.
+    ///
+    /// Returns `None`, without decrypting, if `nonce`'s counter is at or below the highest
+    /// counter already accepted from the peer.
+    pub fn decrypt(
+        &mut self,
+        nonce: &SafeSecretboxNonce,
+        ciphertext: &Ciphertext,
+    ) -> Option<Box<Plaintext>> {
+        let counter = counter_from_nonce(nonce.as_ref());
+        if self.highest_received.is_some_and(|highest| counter <= highest) {
+            return None;
+        }
+
+        let plaintext = safe_secretbox_open(ciphertext, nonce, &self.key)?;
+        self.highest_received = Some(counter);
+        Some(plaintext)
+    }
+}
+
+/// A [`crate::safe_box`] session that sequences nonces with a monotonic counter.
+///
+/// The send counter's parity is fixed from the lexicographic comparison of the two peers'
+/// public keys, following the even/odd convention described in [`crate::safe_box`]'s module
+/// docs, so the two peers never reuse each other's nonces.
+pub struct BoxSession {
+    secret_key: SafeBoxSecretKey,
+    peer_public_key: SafeBoxPublicKey,
+    send_counter: u64,
+    highest_received: Option<u64>,
+}
+
+impl BoxSession {
+    /// Start a new session between `public_key`/`secret_key` and `peer_public_key`.
+    pub fn new(
+        public_key: &SafeBoxPublicKey,
+        secret_key: SafeBoxSecretKey,
+        peer_public_key: SafeBoxPublicKey,
+    ) -> Self {
+        let send_counter = if public_key.as_ref() < peer_public_key.as_ref() {
+            1
+        } else {
+            2
+        };
+
+        BoxSession {
+            secret_key,
+            peer_public_key,
+            send_counter,
+            highest_received: None,
+        }
+    }
+
+    /// Encrypt and authenticate `plaintext`, using and then advancing the session's counter.
+    ///
+    /// Returns the nonce alongside the ciphertext; pass both to the peer's [`Self::decrypt`].
+    pub fn encrypt_next(&mut self, plaintext: &Plaintext) -> (SafeBoxNonce, Box<Ciphertext>) {
+        let nonce = box_counter_nonce(self.send_counter);
+        self.send_counter = self
+            .send_counter
+            .checked_add(2)
+            .expect("BoxSession: counter exhausted");
+
+        let ciphertext = safe_box(plaintext, &nonce, &self.peer_public_key, &self.secret_key);
+        (nonce, ciphertext)
+    }
+
+    /// Decrypt and verify `ciphertext` using `nonce`.
+    ///
+    /// Returns `None`, without decrypting, if `nonce`'s counter is at or below the highest
+    /// counter already accepted from the peer.
+    pub fn decrypt(&mut self, nonce: &SafeBoxNonce, ciphertext: &Ciphertext) -> Option<Box<Plaintext>> {
+        let counter = counter_from_nonce(nonce.as_ref());
+        if self.highest_received.is_some_and(|highest| counter <= highest) {
+            return None;
+        }
+
+        let plaintext = safe_box_open(ciphertext, nonce, &self.peer_public_key, &self.secret_key)?;
+        self.highest_received = Some(counter);
+        Some(plaintext)
+    }
+}
+
+/// Encode `counter` little-endian into the low bytes of a fresh all-zero [`SafeSecretboxNonce`].
+fn secretbox_counter_nonce(counter: u64) -> SafeSecretboxNonce {
+    let mut nonce = sodalite::SecretboxNonce::default();
+    nonce[..8].copy_from_slice(&counter.to_le_bytes());
+    nonce.into()
+}
+
+/// Encode `counter` little-endian into the low bytes of a fresh all-zero [`SafeBoxNonce`].
+fn box_counter_nonce(counter: u64) -> SafeBoxNonce {
+    let mut nonce = sodalite::BoxNonce::default();
+    nonce[..8].copy_from_slice(&counter.to_le_bytes());
+    nonce.into()
+}
+
+/// Decode the little-endian counter from the low 8 bytes of a nonce.
+fn counter_from_nonce(nonce: &[u8]) -> u64 {
+    let mut counter_bytes = [0_u8; 8];
+    counter_bytes.copy_from_slice(&nonce[..8]);
+    u64::from_le_bytes(counter_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+    use alloc::format; // required by proptest macros
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+    use sodalite::SecretboxKey;
+
+    use super::*;
+    use crate::safe_box::safe_box_keypair_seed;
+    use crate::types::SecureSeed;
+
+    /// Property: successive [`SecretboxSession::encrypt_next`] nonces never repeat, and
+    /// [`SecretboxSession::decrypt`] accepts them in order but refuses a replay.
+    #[test]
+    fn prop_secretbox_session_counters_and_replay() {
+        proptest!(|(key: SecretboxKey, messages: Vec<Box<Plaintext>>)| {
+            check(key.into(), messages)?;
+        });
+
+        fn check(key: SafeSecretboxKey, messages: Vec<Box<Plaintext>>) -> TestCaseResult {
+            let mut sender = SecretboxSession::new(key.clone(), SecretboxRole::Initiator);
+            let mut receiver = SecretboxSession::new(key, SecretboxRole::Responder);
+
+            let mut seen_counters = BTreeSet::new();
+            let mut sent = Vec::new();
+
+            for message in &messages {
+                let (nonce, ciphertext) = sender.encrypt_next(message);
+                prop_assert!(seen_counters.insert(counter_from_nonce(nonce.as_ref())));
+
+                let plaintext = receiver
+                    .decrypt(&nonce, &ciphertext)
+                    .expect("decrypt of a fresh message failed");
+                prop_assert_eq!(&plaintext, message);
+
+                sent.push((nonce, ciphertext));
+            }
+
+            // Replaying any previously accepted message must now be refused.
+            for (nonce, ciphertext) in &sent {
+                prop_assert_eq!(receiver.decrypt(nonce, ciphertext), None);
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Property: two [`SecretboxSession`]s sharing a key, with opposite [`SecretboxRole`]s,
+    /// never choose colliding nonces even when both sides send.
+    #[test]
+    fn prop_secretbox_session_roles_never_collide() {
+        proptest!(|(key: SecretboxKey, message_count in 0..8_usize)| {
+            check(key.into(), message_count)?;
+        });
+
+        fn check(key: SafeSecretboxKey, message_count: usize) -> TestCaseResult {
+            let mut initiator = SecretboxSession::new(key.clone(), SecretboxRole::Initiator);
+            let mut responder = SecretboxSession::new(key, SecretboxRole::Responder);
+
+            let mut initiator_counters = BTreeSet::new();
+            let mut responder_counters = BTreeSet::new();
+
+            for i in 0..message_count {
+                let message: Box<Plaintext> = vec![i as u8].into_boxed_slice();
+
+                let (nonce, ciphertext) = initiator.encrypt_next(&message);
+                prop_assert!(initiator_counters.insert(counter_from_nonce(nonce.as_ref())));
+                responder
+                    .decrypt(&nonce, &ciphertext)
+                    .expect("decrypt by the peer failed");
+
+                let (nonce, ciphertext) = responder.encrypt_next(&message);
+                prop_assert!(responder_counters.insert(counter_from_nonce(nonce.as_ref())));
+                initiator
+                    .decrypt(&nonce, &ciphertext)
+                    .expect("decrypt by the peer failed");
+            }
+
+            prop_assert!(initiator_counters.is_disjoint(&responder_counters));
+
+            Ok(())
+        }
+    }
+
+    /// Property: two [`BoxSession`]s for the same pair of peers never choose colliding nonces.
+    #[test]
+    fn prop_box_session_peers_never_collide() {
+        proptest!(|(our_seed: SecureSeed, their_seed: SecureSeed, message_count in 0..8_usize)| {
+            prop_assume!(our_seed != their_seed);
+            check(our_seed.into(), their_seed.into(), message_count)?;
+        });
+
+        fn check(
+            our_seed: crate::types::SafeSecureSeed,
+            their_seed: crate::types::SafeSecureSeed,
+            message_count: usize,
+        ) -> TestCaseResult {
+            let (our_pk, our_sk) = safe_box_keypair_seed(&our_seed);
+            let (their_pk, their_sk) = safe_box_keypair_seed(&their_seed);
+
+            let mut ours = BoxSession::new(&our_pk, our_sk, their_pk.clone());
+            let mut theirs = BoxSession::new(&their_pk, their_sk, our_pk);
+
+            let mut our_counters = BTreeSet::new();
+            let mut their_counters = BTreeSet::new();
+
+            for i in 0..message_count {
+                let message: Box<Plaintext> = vec![i as u8].into_boxed_slice();
+
+                let (nonce, ciphertext) = ours.encrypt_next(&message);
+                prop_assert!(our_counters.insert(counter_from_nonce(nonce.as_ref())));
+                theirs
+                    .decrypt(&nonce, &ciphertext)
+                    .expect("decrypt by the peer failed");
+
+                let (nonce, ciphertext) = theirs.encrypt_next(&message);
+                prop_assert!(their_counters.insert(counter_from_nonce(nonce.as_ref())));
+                ours.decrypt(&nonce, &ciphertext)
+                    .expect("decrypt by the peer failed");
+            }
+
+            prop_assert!(our_counters.is_disjoint(&their_counters));
+
+            Ok(())
+        }
+    }
+}